@@ -32,11 +32,14 @@ impl<'a> From<&'a Contract> for Dispatch<'a> {
 impl<'a> GenerateCode for Dispatch<'a> {
     fn generate_code(&self) -> TokenStream2 {
         let marker = self.generate_external_fn_marker();
+        let collisions = self.generate_selector_collisions();
         let traits = self.generate_external_fn_traits();
         let dispatch = self.generate_dispatch();
         let entry_point = self.generate_entry_point();
 
         quote! {
+            #collisions
+
             #[cfg(not(test))]
             const _: () = {
                 #marker
@@ -69,6 +72,121 @@ fn generate_input_idents(
     (input_idents, pat_idents)
 }
 
+/// Emits the body of a fallback/receive branch: invoke the handler, flush when
+/// it is mutable (mirroring the normal dispatch fragments), then succeed.
+fn generate_special_body(func: &Function) -> TokenStream2 {
+    let fn_name = &func.sig.ident;
+    let flush = if func.sig.is_mut() {
+        quote! {
+            <Storage as liquid_core::storage::Flush>::flush(&mut storage);
+        }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        storage.#fn_name();
+        #flush
+        return Ok(());
+    }
+}
+
+/// Maps a Rust input type to its canonical Solidity ABI name, so that the
+/// hashed selector matches what callers compute the standard way (e.g.
+/// `Address` -> `address`, `u256` -> `uint256`, `Vec<u8>` -> `bytes`).
+fn abi_type_name(ty: &str) -> String {
+    let ty = ty.split_whitespace().collect::<String>();
+
+    if let Some(inner) = ty.strip_prefix("Vec<").and_then(|s| s.strip_suffix('>')) {
+        let elem = abi_type_name(inner);
+        // liquid encodes `Vec<u8>` as the Solidity dynamic `bytes` type, the
+        // same as its dedicated `Bytes`; any other element becomes `T[]`.
+        if elem == "uint8" {
+            return "bytes".to_string();
+        }
+        return format!("{}[]", elem);
+    }
+
+    if let Some(inner) = ty.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        if let Some((elem, len)) = inner.split_once(';') {
+            return format!("{}[{}]", abi_type_name(elem), len);
+        }
+    }
+
+    match ty.as_str() {
+        "Address" | "address" => return "address".to_string(),
+        "String" | "str" => return "string".to_string(),
+        "bool" => return "bool".to_string(),
+        "bytes" | "Bytes" => return "bytes".to_string(),
+        _ => {}
+    }
+
+    // `u8`..`u256` -> `uintN`, `i8`..`i256` -> `intN`.
+    for (prefix, abi) in &[("u", "uint"), ("i", "int")] {
+        if let Some(bits) = ty.strip_prefix(prefix) {
+            if !bits.is_empty() && bits.bytes().all(|b| b.is_ascii_digit()) {
+                return format!("{}{}", abi, bits);
+            }
+        }
+    }
+
+    ty
+}
+
+/// Builds the canonical signature string `name(type1,type2,...)` used to derive
+/// Solidity/ABI-compatible selectors, with each input type normalized to its
+/// ABI name.
+fn canonical_signature(fn_name: &proc_macro2::Ident, input_tys: &[TokenStream2]) -> String {
+    let tys = input_tys
+        .iter()
+        .map(|ty| abi_type_name(&ty.to_string()))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}({})", fn_name, tys)
+}
+
+/// First 4 bytes of the keccak256 hash of `sig`, as used in the non-`gm` build.
+fn keccak_selector(sig: &str) -> u32 {
+    use tiny_keccak::{Hasher, Keccak};
+
+    let mut keccak = Keccak::v256();
+    let mut output = [0u8; 32];
+    keccak.update(sig.as_bytes());
+    keccak.finalize(&mut output);
+    u32::from_be_bytes([output[0], output[1], output[2], output[3]])
+}
+
+/// First 4 bytes of the SM3 hash of `sig`, as used when the `gm` feature is on.
+fn sm3_selector(sig: &str) -> u32 {
+    use sm3::{Digest, Sm3};
+
+    let output = Sm3::digest(sig.as_bytes());
+    u32::from_be_bytes([output[0], output[1], output[2], output[3]])
+}
+
+/// Emits a `FnSelector` impl whose `SELECTOR` is the hashed signature selector,
+/// splitting on the `gm` feature exactly like `hash_type()` in the entry point.
+fn generate_signature_selector(
+    fn_marker: &TokenStream2,
+    fn_name: &proc_macro2::Ident,
+    input_tys: &[TokenStream2],
+) -> TokenStream2 {
+    let sig = canonical_signature(fn_name, input_tys);
+    let keccak = keccak_selector(&sig);
+    let sm3 = sm3_selector(&sig);
+
+    quote! {
+        #[cfg(not(feature = "gm"))]
+        impl liquid_lang::FnSelector for #fn_marker {
+            const SELECTOR: u32 = #keccak;
+        }
+        #[cfg(feature = "gm")]
+        impl liquid_lang::FnSelector for #fn_marker {
+            const SELECTOR: u32 = #sm3;
+        }
+    }
+}
+
 impl<'a> Dispatch<'a> {
     fn generate_external_fn_marker(&self) -> TokenStream2 {
         quote! {
@@ -96,6 +214,73 @@ impl<'a> Dispatch<'a> {
         }
     }
 
+    /// Detects external functions that map to the same signature-hashed
+    /// selector and reports each clash as a spanned `compile_error!` on the
+    /// later function's name, so dispatch ambiguity fails the build instead of
+    /// silently shadowing a handler at runtime.
+    ///
+    /// Position-dependent `fn_id` selectors are unique by construction, so the
+    /// check only runs when ABI-compatible selectors are enabled. The keccak
+    /// and SM3 families are checked independently and each set of diagnostics
+    /// is `#[cfg]`-gated exactly like the selectors emitted in
+    /// `generate_signature_selector`, so the build being compiled always sees
+    /// the check for the family actually baked into it.
+    fn generate_selector_collisions(&self) -> TokenStream2 {
+        if !self.contract.meta_info.abi_compatible_selectors {
+            return quote! {};
+        }
+
+        let keccak_errors = self.selector_collisions(keccak_selector);
+        let sm3_errors = self.selector_collisions(sm3_selector);
+
+        quote! {
+            #(
+                #[cfg(not(feature = "gm"))]
+                #keccak_errors
+            )*
+            #(
+                #[cfg(feature = "gm")]
+                #sm3_errors
+            )*
+        }
+    }
+
+    /// Computes each external function's selector with `hash` and returns a
+    /// spanned `compile_error!` for every clash, anchored on the later
+    /// function's name.
+    fn selector_collisions(&self, hash: fn(&str) -> u32) -> Vec<TokenStream2> {
+        let mut seen = Vec::<(u32, String)>::new();
+        let mut errors = Vec::new();
+
+        for func in self
+            .contract
+            .functions
+            .iter()
+            .filter(|func| matches!(&func.kind, FunctionKind::External(_)))
+        {
+            let sig = &func.sig;
+            let name = sig.ident.to_string();
+            let input_tys = utils::generate_input_tys(sig, true);
+            let signature = canonical_signature(&sig.ident, &input_tys);
+            let selector = hash(&signature);
+
+            let collision = seen.iter().find(|(seen_selector, _)| *seen_selector == selector);
+            if let Some((_, other)) = collision {
+                let msg = format!(
+                    "selector collision between external functions `{}` and `{}`",
+                    other, name
+                );
+                errors.push(quote_spanned! { sig.ident.span() =>
+                    compile_error!(#msg);
+                });
+            }
+
+            seen.push((selector, name));
+        }
+
+        errors
+    }
+
     fn generate_external_fn_trait(&self, func: &Function) -> TokenStream2 {
         let fn_id = match &func.kind {
             FunctionKind::External(fn_id) => fn_id,
@@ -130,7 +315,11 @@ impl<'a> Dispatch<'a> {
             }
         };
 
-        let selectors = utils::generate_ty_mapping(*fn_id, &sig.ident, &input_tys);
+        let selectors = if self.contract.meta_info.abi_compatible_selectors {
+            generate_signature_selector(&fn_marker, &sig.ident, &input_tys)
+        } else {
+            utils::generate_ty_mapping(*fn_id, &sig.ident, &input_tys)
+        };
         let is_mut = sig.is_mut();
         let mutability = quote_spanned! { span =>
             impl liquid_lang::FnMutability for #fn_marker {
@@ -210,14 +399,44 @@ impl<'a> Dispatch<'a> {
 
         let constr_input_ty_checker = self.generate_constr_input_ty_checker();
 
+        let receive = self
+            .contract
+            .functions
+            .iter()
+            .find(|func| matches!(func.kind, FunctionKind::Receive));
+        let fallback = self
+            .contract
+            .functions
+            .iter()
+            .find(|func| matches!(func.kind, FunctionKind::Fallback));
+
+        // Empty calldata carries no selector, so `get_call_data` fails to read
+        // one before we ever inspect `data`; that failure is how we recognize
+        // the receive case.
+        let receive_branch = match receive {
+            Some(func) => generate_special_body(func),
+            None => quote! {
+                return Err(liquid_lang::DispatchError::CouldNotReadInput);
+            },
+        };
+
+        let fallback_fragment = match fallback {
+            Some(func) => generate_special_body(func),
+            None => quote! { Err(liquid_lang::DispatchError::UnknownSelector) },
+        };
+
         quote! {
             #constr_input_ty_checker
 
             impl Storage {
                 pub fn dispatch() -> liquid_lang::DispatchResult {
                     let mut storage = <Storage as liquid_core::storage::New>::new();
-                    let call_data = liquid_core::env::get_call_data(liquid_core::env::CallMode::Call)
-                        .map_err(|_| liquid_lang::DispatchError::CouldNotReadInput)?;
+                    let call_data = match liquid_core::env::get_call_data(liquid_core::env::CallMode::Call) {
+                        Ok(call_data) => call_data,
+                        Err(_) => {
+                            #receive_branch
+                        }
+                    };
                     let selector = call_data.selector;
                     let data = call_data.data;
 
@@ -225,7 +444,7 @@ impl<'a> Dispatch<'a> {
                         #fragments
                     )*
 
-                    Err(liquid_lang::DispatchError::UnknownSelector)
+                    #fallback_fragment
                 }
             }
         }
@@ -0,0 +1,64 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote_spanned;
+use syn::{parse2, spanned::Spanned, LitStr};
+
+/// Number of hexadecimal digits expected in a hash literal, i.e. two per byte.
+const HASH_DIGITS: usize = liquid_primitives::types::HASH_LENGTH * 2;
+
+/// Expands `hash!("0x…")` into a `const`-constructible [`Hash`] by parsing and
+/// validating the hex literal during macro expansion.
+///
+/// Any malformed literal is turned into a spanned `compile_error!` pointing at
+/// the offending token, so a typo in a baked-in hash is caught at build time
+/// instead of trapping on-chain.
+///
+/// [`Hash`]: liquid_primitives::types::Hash
+pub fn generate(input: TokenStream2) -> TokenStream2 {
+    let lit = match parse2::<LitStr>(input.clone()) {
+        Ok(lit) => lit,
+        Err(_) => {
+            return quote_spanned! { input.span() =>
+                compile_error!("expected a string literal containing a hexadecimal hash")
+            };
+        }
+    };
+
+    let span = lit.span();
+    let value = lit.value();
+    let digits = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .unwrap_or(&value);
+
+    if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return quote_spanned! { span =>
+            compile_error!("expected a hexadecimal hash, found a non-hex digit")
+        };
+    }
+
+    if digits.len() != HASH_DIGITS {
+        let msg = format!("expected {} hex digits, found {}", HASH_DIGITS, digits.len());
+        return quote_spanned! { span => compile_error!(#msg) };
+    }
+
+    let bytes = (0..digits.len() / 2).map(|i| {
+        let byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).unwrap();
+        quote_spanned! { span => #byte }
+    });
+
+    quote_spanned! { span =>
+        liquid_primitives::types::Hash::new([#(#bytes,)*])
+    }
+}
@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use proc_macro2::Span;
+use syn::{punctuated::Punctuated, spanned::Spanned, Token};
+
+/// A liquid contract lowered into its intermediate representation.
+pub struct Contract {
+    pub meta_info: MetaInfo,
+    pub storage: Storage,
+    pub constructor: Function,
+    pub functions: Vec<Function>,
+}
+
+/// Contract-level configuration gathered from the `#[liquid(contract)]`
+/// attribute.
+pub struct MetaInfo {
+    /// Opt-in to Solidity/ABI-compatible, signature-hashed selectors instead of
+    /// the position-dependent `fn_id` selectors.
+    pub abi_compatible_selectors: bool,
+}
+
+/// The contract's storage struct.
+pub struct Storage {
+    pub public_fields: Vec<syn::Field>,
+}
+
+/// A single function item of the contract.
+pub struct Function {
+    pub kind: FunctionKind,
+    pub sig: Signature,
+}
+
+impl Function {
+    pub fn span(&self) -> Span {
+        self.sig.span()
+    }
+}
+
+/// How a contract function participates in dispatch.
+///
+/// A function is `External` (callable via its selector) unless the user marks
+/// it with `#[liquid(fallback)]` or `#[liquid(receive)]`, in which case it
+/// handles unmatched selectors or empty calldata respectively.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionKind {
+    Constructor,
+    External(usize),
+    Fallback,
+    Receive,
+}
+
+impl FunctionKind {
+    /// Classifies a function from its liquid attributes, falling back to a
+    /// position-dependent `External(fn_id)` when none of the special markers
+    /// are present.
+    pub fn from_attributes(attrs: &[syn::Attribute], fn_id: usize) -> Self {
+        for attr in attrs {
+            if !attr.path.is_ident("liquid") {
+                continue;
+            }
+
+            if let Ok(syn::Meta::List(list)) = attr.parse_meta() {
+                for nested in &list.nested {
+                    if let syn::NestedMeta::Meta(syn::Meta::Path(path)) = nested {
+                        if path.is_ident("fallback") {
+                            return FunctionKind::Fallback;
+                        }
+                        if path.is_ident("receive") {
+                            return FunctionKind::Receive;
+                        }
+                    }
+                }
+            }
+        }
+
+        FunctionKind::External(fn_id)
+    }
+}
+
+/// The signature of a contract function.
+pub struct Signature {
+    pub ident: syn::Ident,
+    pub inputs: Punctuated<FnArg, Token![,]>,
+    pub output: syn::ReturnType,
+    mutates: bool,
+}
+
+impl Signature {
+    pub fn span(&self) -> Span {
+        self.ident.span()
+    }
+
+    pub fn is_mut(&self) -> bool {
+        self.mutates
+    }
+}
+
+/// A function argument, mirroring `syn::FnArg` but carrying the bound ident.
+pub enum FnArg {
+    Receiver(syn::Receiver),
+    Typed(IdentType),
+}
+
+/// A typed argument with its binding ident, e.g. `amount: u256`.
+pub struct IdentType {
+    pub ident: syn::Ident,
+    pub ty: syn::Type,
+}
@@ -0,0 +1,34 @@
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+mod hash;
+mod ir;
+
+/// Parses and validates a hexadecimal hash literal at compile time, expanding
+/// it into a `const`-constructible `Hash`.
+///
+/// A malformed literal (wrong length or a non-hex digit) is reported as a
+/// spanned `compile_error!`, so a typo in a baked-in hash fails the build
+/// instead of trapping on-chain.
+///
+/// ```ignore
+/// const CODE_HASH: liquid_primitives::types::Hash =
+///     hash!("0x27772adc63db07aae765b71eb2b533064fa781bd57457e1b138592d8198d0959");
+/// ```
+#[proc_macro]
+pub fn hash(input: TokenStream) -> TokenStream {
+    hash::generate(input.into()).into()
+}
@@ -0,0 +1,6 @@
+use liquid_lang_macro::hash;
+
+fn main() {
+    // 62 hex digits instead of 64.
+    let _ = hash!("0x772adc63db07aae765b71eb2b533064fa781bd57457e1b138592d8198d0959");
+}
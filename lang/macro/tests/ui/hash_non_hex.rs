@@ -0,0 +1,6 @@
+use liquid_lang_macro::hash;
+
+fn main() {
+    // 'z' is not a hexadecimal digit.
+    let _ = hash!("0xz7772adc63db07aae765b71eb2b533064fa781bd57457e1b138592d8198d095");
+}
@@ -28,6 +28,14 @@ impl Default for Hash {
 }
 
 impl Hash {
+    /// Constructs a `Hash` from its raw bytes.
+    ///
+    /// This is a `const fn` so that the `hash!` macro can expand a validated
+    /// hex literal into a `const`-constructible value.
+    pub const fn new(bytes: [u8; HASH_LENGTH]) -> Self {
+        Self(bytes)
+    }
+
     pub fn as_ptr(&self) -> *const [u8; HASH_LENGTH] {
         &self.0 as *const _
     }